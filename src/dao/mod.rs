@@ -0,0 +1,14 @@
+use color_eyre::Result;
+
+use crate::data_pull::serde_models::{League, Player, Team, Tournament};
+
+/// Persists fetched LoLEsports entities to the backing database.
+///
+/// Implemented per storage backend; `DataPull` is generic over anything
+/// implementing this trait so the fetch logic stays decoupled from storage.
+#[async_trait::async_trait]
+pub trait DatabaseOps {
+    async fn insert_leagues(&self, leagues: &[League]) -> Result<()>;
+    async fn insert_tournaments(&self, tournaments: &[Tournament]) -> Result<()>;
+    async fn insert_teams_and_players(&self, teams: &[Team], players: &[Player]) -> Result<()>;
+}
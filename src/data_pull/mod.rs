@@ -0,0 +1 @@
+pub mod serde_models;
@@ -0,0 +1,362 @@
+use std::fmt;
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// A LoLEsports identifier. The API encodes these as JSON strings even though
+/// they're numeric, so we parse through a `String` on the way in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LolesportsId(pub u64);
+
+/// Hand-written rather than derived so serializing stays in sync with the
+/// string form `Deserialize` expects — the derive would emit a bare JSON
+/// number and round-tripping would fail.
+impl Serialize for LolesportsId {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.0.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for LolesportsId {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = String::deserialize(deserializer)?;
+        raw.parse::<u64>()
+            .map(LolesportsId)
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+/// A player's competitive position. Unrecognized values (new roles, data
+/// entry typos) fall back to `Unknown` instead of failing the whole pull —
+/// the same trick the API itself uses when it introduces new enum values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Role {
+    Top,
+    Jungle,
+    Mid,
+    Bot,
+    Support,
+    Unknown(String),
+}
+
+impl AsRef<str> for Role {
+    fn as_ref(&self) -> &str {
+        match self {
+            Role::Top => "top",
+            Role::Jungle => "jungle",
+            Role::Mid => "mid",
+            Role::Bot => "bot",
+            Role::Support => "support",
+            Role::Unknown(raw) => raw,
+        }
+    }
+}
+
+impl fmt::Display for Role {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_ref())
+    }
+}
+
+/// Hand-written rather than derived so round-tripping stays in sync with
+/// `AsRef<str>`/`Display` — the derive would serialize `Unknown(raw)` as
+/// `{"Unknown":raw}` and the known variants in their Rust casing instead of
+/// the original API text.
+impl Serialize for Role {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_ref())
+    }
+}
+
+impl<'de> Deserialize<'de> for Role {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = String::deserialize(deserializer)?;
+        Ok(match raw.to_ascii_lowercase().as_str() {
+            "top" => Role::Top,
+            "jungle" => Role::Jungle,
+            "mid" => Role::Mid,
+            "bot" | "adc" => Role::Bot,
+            "support" => Role::Support,
+            _ => Role::Unknown(raw),
+        })
+    }
+}
+
+/// A league's competitive region. Unrecognized values fall back to
+/// `Unknown` instead of failing the whole pull.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Region {
+    International,
+    Emea,
+    Americas,
+    Korea,
+    China,
+    Unknown(String),
+}
+
+impl AsRef<str> for Region {
+    fn as_ref(&self) -> &str {
+        match self {
+            Region::International => "INTERNATIONAL",
+            Region::Emea => "EMEA",
+            Region::Americas => "AMERICAS",
+            Region::Korea => "KOREA",
+            Region::China => "CHINA",
+            Region::Unknown(raw) => raw,
+        }
+    }
+}
+
+impl fmt::Display for Region {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_ref())
+    }
+}
+
+/// Hand-written rather than derived — see [`Role`]'s `Serialize` impl.
+impl Serialize for Region {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_ref())
+    }
+}
+
+impl<'de> Deserialize<'de> for Region {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = String::deserialize(deserializer)?;
+        Ok(match raw.to_ascii_uppercase().as_str() {
+            "INTERNATIONAL" => Region::International,
+            "EMEA" => Region::Emea,
+            "AMERICAS" => Region::Americas,
+            "KOREA" => Region::Korea,
+            "CHINA" => Region::China,
+            _ => Region::Unknown(raw),
+        })
+    }
+}
+
+/// A team's roster status. Unrecognized values fall back to `Unknown`
+/// instead of failing the whole pull.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TeamStatus {
+    Active,
+    Archived,
+    Unknown(String),
+}
+
+impl AsRef<str> for TeamStatus {
+    fn as_ref(&self) -> &str {
+        match self {
+            TeamStatus::Active => "active",
+            TeamStatus::Archived => "archived",
+            TeamStatus::Unknown(raw) => raw,
+        }
+    }
+}
+
+impl fmt::Display for TeamStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_ref())
+    }
+}
+
+/// Hand-written rather than derived — see [`Role`]'s `Serialize` impl.
+impl Serialize for TeamStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_ref())
+    }
+}
+
+impl<'de> Deserialize<'de> for TeamStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = String::deserialize(deserializer)?;
+        Ok(match raw.to_ascii_lowercase().as_str() {
+            "active" => TeamStatus::Active,
+            "archived" => TeamStatus::Archived,
+            _ => TeamStatus::Unknown(raw),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Wrapper<T> {
+    pub data: T,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Leagues {
+    pub leagues: Vec<League>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct League {
+    pub id: LolesportsId,
+    pub slug: String,
+    pub name: String,
+    pub region: Region,
+    pub image: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct LeagueForTournaments {
+    pub id: LolesportsId,
+    pub slug: String,
+    pub tournaments: Vec<Tournament>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Tournament {
+    pub id: LolesportsId,
+    pub slug: String,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct HomeLeague {
+    pub name: String,
+    pub region: Region,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Team {
+    pub id: LolesportsId,
+    pub slug: String,
+    pub name: String,
+    pub code: String,
+    pub image: String,
+    pub alternative_image: Option<String>,
+    pub background_image: Option<String>,
+    pub status: TeamStatus,
+    pub home_league: Option<HomeLeague>,
+    pub players: Vec<Player>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Player {
+    pub id: LolesportsId,
+    pub summoner_name: String,
+    pub first_name: String,
+    pub last_name: String,
+    pub image: Option<String>,
+    pub role: Role,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TeamsPlayers {
+    pub teams: Vec<Team>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ScheduleOutter {
+    pub schedule: Schedule,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Schedule {
+    pub events: Vec<Event>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Event {
+    pub id: String,
+    pub start_time: DateTime<Utc>,
+    pub state: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct EventOutter {
+    pub event: EventDetails,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct EventDetails {
+    pub id: String,
+    pub state: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct LiveScheduleOutter {
+    pub schedule: Schedule,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unknown_role_falls_back_to_unknown_variant() {
+        let role: Role = serde_json::from_str("\"coach\"").unwrap();
+        assert_eq!(role, Role::Unknown("coach".to_string()));
+    }
+
+    #[test]
+    fn known_role_displays_as_the_original_api_text() {
+        let role: Role = serde_json::from_str("\"mid\"").unwrap();
+        assert_eq!(role.to_string(), "mid");
+    }
+
+    #[test]
+    fn unknown_region_falls_back_to_unknown_variant() {
+        let region: Region = serde_json::from_str("\"OCEANIA\"").unwrap();
+        assert_eq!(region, Region::Unknown("OCEANIA".to_string()));
+    }
+
+    #[test]
+    fn unknown_team_status_falls_back_to_unknown_variant() {
+        let status: TeamStatus = serde_json::from_str("\"suspended\"").unwrap();
+        assert_eq!(status, TeamStatus::Unknown("suspended".to_string()));
+    }
+
+    #[test]
+    fn known_role_serializes_as_the_original_api_text() {
+        assert_eq!(serde_json::to_string(&Role::Mid).unwrap(), "\"mid\"");
+    }
+
+    #[test]
+    fn unknown_role_serializes_back_to_its_raw_text() {
+        let role = Role::Unknown("coach".to_string());
+        assert_eq!(serde_json::to_string(&role).unwrap(), "\"coach\"");
+    }
+
+    #[test]
+    fn known_region_serializes_as_the_original_api_text() {
+        assert_eq!(serde_json::to_string(&Region::Emea).unwrap(), "\"EMEA\"");
+    }
+
+    #[test]
+    fn known_team_status_serializes_as_the_original_api_text() {
+        assert_eq!(serde_json::to_string(&TeamStatus::Active).unwrap(), "\"active\"");
+    }
+
+    #[test]
+    fn lolesports_id_round_trips_through_its_string_form() {
+        let id = LolesportsId(123);
+        let json = serde_json::to_string(&id).unwrap();
+        assert_eq!(json, "\"123\"");
+        assert_eq!(serde_json::from_str::<LolesportsId>(&json).unwrap(), id);
+    }
+}
@@ -0,0 +1,55 @@
+use std::time::Duration;
+
+use reqwest::StatusCode;
+use thiserror::Error;
+
+/// Typed failures from calling the LoLEsports API. `make_get_request` maps
+/// HTTP status codes onto these instead of handing every caller a `Response`
+/// and letting `.json()` produce a confusing deserialize error on a `404`.
+#[derive(Debug, Error)]
+pub enum ApiError {
+    #[error("resource not found")]
+    NotFound,
+    #[error("request was not authorized — check the API key")]
+    Unauthorized,
+    #[error("rate limited after repeated 429s, retry after {retry_after:?}")]
+    RateLimited { retry_after: Duration },
+    #[error("server responded with {0}")]
+    ServerError(StatusCode),
+    #[error("failed to deserialize response body: {0}")]
+    Deserialize(String),
+}
+
+/// Maps a non-2xx status that isn't already handled (304/429 are resolved
+/// before we get here) onto the closest `ApiError` variant.
+pub fn classify_status(status: StatusCode) -> ApiError {
+    match status {
+        StatusCode::NOT_FOUND => ApiError::NotFound,
+        StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => ApiError::Unauthorized,
+        status => ApiError::ServerError(status),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn not_found_maps_to_the_not_found_variant() {
+        assert!(matches!(classify_status(StatusCode::NOT_FOUND), ApiError::NotFound));
+    }
+
+    #[test]
+    fn unauthorized_and_forbidden_both_map_to_unauthorized() {
+        assert!(matches!(classify_status(StatusCode::UNAUTHORIZED), ApiError::Unauthorized));
+        assert!(matches!(classify_status(StatusCode::FORBIDDEN), ApiError::Unauthorized));
+    }
+
+    #[test]
+    fn anything_else_falls_back_to_server_error_with_the_original_status() {
+        match classify_status(StatusCode::BAD_GATEWAY) {
+            ApiError::ServerError(status) => assert_eq!(status, StatusCode::BAD_GATEWAY),
+            other => panic!("expected ServerError, got {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,5 @@
+pub mod dao;
+pub mod data_pull;
+pub mod error;
+pub mod service;
+pub mod utils;
@@ -0,0 +1,147 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use color_eyre::Result;
+use serde::{Deserialize, Serialize};
+
+const INDEX_FILE: &str = "http_cache.json";
+
+/// A single cached response: its validators (for conditional GETs) and, when
+/// `Cache-Control` allows it, a `max_age` that lets us skip the round-trip
+/// entirely while still fresh.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CacheEntry {
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+    pub max_age: Option<u64>,
+    pub fetched_at: i64,
+    pub body: String,
+}
+
+impl CacheEntry {
+    pub fn is_fresh(&self) -> bool {
+        match self.max_age {
+            Some(max_age) => {
+                let age = chrono::Utc::now().timestamp() - self.fetched_at;
+                age >= 0 && (age as u64) < max_age
+            }
+            None => false,
+        }
+    }
+}
+
+/// An on-disk `ETag`/`Last-Modified` cache for `make_get_request`, so repeat
+/// syncs of slow-changing data (league and team rosters) can skip or shrink
+/// their network round-trips.
+pub struct HttpCache {
+    dir: PathBuf,
+}
+
+impl HttpCache {
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    fn index_path(&self) -> PathBuf {
+        self.dir.join(INDEX_FILE)
+    }
+
+    async fn load_index(&self) -> HashMap<String, CacheEntry> {
+        match tokio::fs::read(self.index_path()).await {
+            Ok(bytes) => serde_json::from_slice(&bytes).unwrap_or_default(),
+            Err(_) => HashMap::new(),
+        }
+    }
+
+    async fn save_index(&self, index: &HashMap<String, CacheEntry>) -> Result<()> {
+        tokio::fs::create_dir_all(&self.dir).await?;
+        let bytes = serde_json::to_vec_pretty(index)?;
+        tokio::fs::write(self.index_path(), bytes).await?;
+        Ok(())
+    }
+
+    pub async fn get(&self, key: &str) -> Option<CacheEntry> {
+        self.load_index().await.remove(key)
+    }
+
+    pub async fn put(&self, key: &str, entry: CacheEntry) -> Result<()> {
+        let mut index = self.load_index().await;
+        index.insert(key.to_string(), entry);
+        self.save_index(&index).await
+    }
+}
+
+/// Parses a `Cache-Control` header value for the two directives we honor.
+/// Returns `(no_store, max_age)`.
+pub fn parse_cache_control(value: &str) -> (bool, Option<u64>) {
+    let mut no_store = false;
+    let mut max_age = None;
+
+    for directive in value.split(',') {
+        let directive = directive.trim();
+        if directive.eq_ignore_ascii_case("no-store") {
+            no_store = true;
+        } else if let Some(seconds) = directive.strip_prefix("max-age=") {
+            max_age = seconds.trim().parse().ok();
+        }
+    }
+
+    (no_store, max_age)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_no_store_and_max_age_together() {
+        assert_eq!(
+            parse_cache_control("no-store, max-age=60"),
+            (true, Some(60))
+        );
+    }
+
+    #[test]
+    fn defaults_to_not_fresh_when_no_directives_are_present() {
+        assert_eq!(parse_cache_control("private"), (false, None));
+    }
+
+    #[test]
+    fn entry_without_max_age_is_never_considered_fresh() {
+        let entry = CacheEntry {
+            etag: None,
+            last_modified: None,
+            max_age: None,
+            fetched_at: chrono::Utc::now().timestamp(),
+            body: String::new(),
+        };
+
+        assert!(!entry.is_fresh());
+    }
+
+    #[test]
+    fn entry_within_max_age_is_fresh() {
+        let entry = CacheEntry {
+            etag: None,
+            last_modified: None,
+            max_age: Some(60),
+            fetched_at: chrono::Utc::now().timestamp(),
+            body: String::new(),
+        };
+
+        assert!(entry.is_fresh());
+    }
+
+    #[test]
+    fn entry_past_max_age_is_stale() {
+        let entry = CacheEntry {
+            etag: None,
+            last_modified: None,
+            max_age: Some(60),
+            fetched_at: chrono::Utc::now().timestamp() - 120,
+            body: String::new(),
+        };
+
+        assert!(!entry.is_fresh());
+    }
+}
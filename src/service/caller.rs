@@ -1,53 +1,399 @@
-use chrono::Local;
-use reqwest::{Error, Response};
+use chrono::{Local, Utc};
+use rand::Rng;
+use reqwest::StatusCode;
+use serde::de::DeserializeOwned;
 use serde::Serialize;
 use std::fmt::Debug;
+use std::sync::Arc;
 use std::time::Duration;
 
-use crate::utils::constants::lolesports;
-use color_eyre::{eyre::Context, Result};
+use crate::error::{classify_status, ApiError};
+use crate::service::cache::{parse_cache_control, CacheEntry, HttpCache};
+use crate::service::rate_limiter::{Admission, RateLimiter};
+use crate::service::transport::{ConditionalHeaders, HttpResponse, HttpTransport, TransportError};
+use color_eyre::{eyre::eyre, eyre::Context, Result};
+use tokio::sync::Mutex;
 use tokio::time::sleep;
 
-pub async fn make_get_request<T>(url: &str, args: Option<&T>) -> Result<Response>
+/// Transient failures (timeouts, connection errors, `5xx`) are retried this
+/// many times before giving up.
+const MAX_RETRIES: u32 = 5;
+const BASE_BACKOFF: Duration = Duration::from_millis(500);
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// `429`s are retried by blocking the shared rate limiter, but if the key is
+/// still rate limited after this many consecutive `429`s we give up rather
+/// than loop forever.
+const MAX_RATE_LIMIT_RETRIES: u32 = 3;
+
+/// Upper bound for any wait derived from server-controlled input (a `429`'s
+/// `Retry-After`, or the window in a learned `X-Rate-Limit`). Both end up as
+/// `Instant::now() + wait` in `RateLimiter::penalize`, which panics on
+/// overflow — an adversarial or malformed header (e.g.
+/// `Retry-After: 18446744073709551615`) must not be able to reach it
+/// unclamped.
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(300);
+
+/// Either a live HTTP response or a body served straight out of the on-disk
+/// cache (a `304`, or an entry still within its `max-age`). Callers don't
+/// need to care which: `.json()` handles both.
+pub enum ApiResponse {
+    Fresh(HttpResponse),
+    Cached(String),
+}
+
+impl ApiResponse {
+    pub async fn json<T: DeserializeOwned>(self) -> Result<T> {
+        match self {
+            ApiResponse::Fresh(response) => serde_json::from_slice(&response.body)
+                .map_err(|e| ApiError::Deserialize(e.to_string()).into()),
+            ApiResponse::Cached(body) => serde_json::from_str(&body)
+                .map_err(|e| ApiError::Deserialize(e.to_string()).into()),
+        }
+    }
+}
+
+/// Builds the per-endpoint key buckets are tracked under, e.g. `GET:/getLeagues`.
+/// Derived from the URL path, so callers whose path embeds a per-request id
+/// (e.g. a game id in a feed URL) must pass an explicit `bucket_override` to
+/// `make_get_request` instead — otherwise every id would get its own bucket
+/// rather than sharing the cap the limiter is meant to track.
+fn bucket_key(url: &str) -> String {
+    let path = url
+        .split_once("://")
+        .and_then(|(_, rest)| rest.split_once('/'))
+        .map(|(_, path)| path)
+        .unwrap_or(url);
+    format!("GET:/{path}")
+}
+
+/// Builds the cache key a given request's validators/body are stored under.
+fn cache_key(url: &str, query: Option<&str>) -> String {
+    format!("{url}|{}", query.unwrap_or_default())
+}
+
+/// Reads the seconds-based `Retry-After` header, defaulting to 1s if the
+/// server sent a `429` without one, and capping it at `MAX_RETRY_AFTER` since
+/// this value is server-controlled.
+fn retry_after(response: &HttpResponse) -> Duration {
+    response
+        .headers
+        .get("retry-after")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse::<u64>().ok())
+        .map(Duration::from_secs)
+        .unwrap_or(Duration::from_secs(1))
+        .min(MAX_RETRY_AFTER)
+}
+
+/// Parses Riot-style `X-Method-Rate-Limit`/`X-Rate-Limit` headers, e.g.
+/// `"100:10,1000:600"` (100 requests per 10s, 1000 per 600s), so the limiter
+/// can learn the real cap instead of guessing. Only the first window is used,
+/// capped at `MAX_RETRY_AFTER` since this value is server-controlled.
+fn method_rate_limit(response: &HttpResponse) -> Option<(u32, Duration)> {
+    let header = response
+        .headers
+        .get("x-method-rate-limit")
+        .or_else(|| response.headers.get("x-rate-limit"))?
+        .to_str()
+        .ok()?;
+
+    let (cap, interval) = header.split(',').next()?.split_once(':')?;
+    let cap = cap.trim().parse().ok()?;
+    let interval = Duration::from_secs(interval.trim().parse().ok()?).min(MAX_RETRY_AFTER);
+    Some((cap, interval))
+}
+
+/// `base * 2^attempt`, capped at `MAX_BACKOFF`, plus a random `0..base`
+/// jitter so many concurrent team fetches hitting a `5xx` at once don't all
+/// retry in lockstep.
+fn backoff_with_jitter(attempt: u32) -> Duration {
+    let exponential = BASE_BACKOFF
+        .saturating_mul(2u32.saturating_pow(attempt))
+        .min(MAX_BACKOFF);
+    let jitter = Duration::from_millis(rand::thread_rng().gen_range(0..=BASE_BACKOFF.as_millis() as u64));
+    exponential + jitter
+}
+
+pub async fn make_get_request<T>(
+    url: &str,
+    args: Option<&T>,
+    rate_limiter: &Arc<Mutex<RateLimiter>>,
+    cache: Option<&HttpCache>,
+    transport: &dyn HttpTransport,
+    bucket_override: Option<&str>,
+) -> Result<ApiResponse>
 where
     T: Serialize + Debug,
 {
-    let client = reqwest::Client::builder()
-        .timeout(Duration::from_secs(15))
-        .build()?;
+    let query = args
+        .map(|args| {
+            serde_urlencoded::to_string(args)
+                .with_context(|| format!("Failed to encode query arguments {args:?}"))
+        })
+        .transpose()?;
 
-    let mut attempts = 2;
-    let retry_duration = Duration::from_secs(5);
+    let key = cache_key(url, query.as_deref());
+    let cached = match cache {
+        Some(cache) => cache.get(&key).await,
+        None => None,
+    };
 
-    loop {
-        let mut b = client
-            .get(url)
-            .header("x-api-key", "0TvQnueqKa5mxJntVWt0w4LpLfEkrV1Ta8rQBb9Z");
+    if let Some(entry) = &cached {
+        if entry.is_fresh() {
+            return Ok(ApiResponse::Cached(entry.body.clone()));
+        }
+    }
+
+    let conditional = cached.as_ref().map(|entry| ConditionalHeaders {
+        if_none_match: entry.etag.clone(),
+        if_modified_since: entry.last_modified.clone(),
+    });
+
+    let bucket = bucket_override.map(str::to_string).unwrap_or_else(|| bucket_key(url));
+    let mut retries = 0;
+    let mut rate_limit_retries = 0;
 
-        if let Some(arguments) = args {
-            b = b.query(arguments);
+    loop {
+        // Only hold the limiter's lock long enough to check/reserve a slot —
+        // sleeping while holding it would block every other bucket (every
+        // other endpoint, every other in-flight fetch) until this one's
+        // window rolls over.
+        loop {
+            let admission = rate_limiter.lock().await.check(&bucket);
+            match admission {
+                Admission::Ready => break,
+                Admission::Wait(wait) => sleep(wait).await,
+            }
         }
 
-        let result = b.send().await;
+        let result = transport.get(url, query.as_deref(), conditional.as_ref()).await;
 
         match result {
-            Ok(response) => return Ok(response),
-            Err(e) => {
-                if e.is_timeout() && attempts > 0 {
-                    attempts -= 1;
-                    println!(
-                        "{} - Request to {} with args {:?} timed out ",
-                        Local::now().format("%Y-%m-%d %H:%M:%S.%f"),
-                        &url,
-                        args
-                    );
-
-                    sleep(retry_duration).await;
-                } else {
-                    return Err(e)
-                        .with_context(|| format!("Failed to request data from the LoLEsports API:{url:?} with args -> {args:?}"));
+            Ok(response) if response.status == StatusCode::TOO_MANY_REQUESTS => {
+                let wait = retry_after(&response);
+                let limit = method_rate_limit(&response);
+
+                if rate_limit_retries >= MAX_RATE_LIMIT_RETRIES {
+                    return Err(ApiError::RateLimited { retry_after: wait }.into());
                 }
+                rate_limit_retries += 1;
+
+                println!(
+                    "{} - Request to {} with args {:?} was rate-limited, backing off for {:?}",
+                    Local::now().format("%Y-%m-%d %H:%M:%S.%f"),
+                    &url,
+                    args,
+                    wait
+                );
+
+                rate_limiter.lock().await.penalize(&bucket, wait, limit);
+                sleep(wait).await;
+            }
+            Ok(response) if response.status == StatusCode::NOT_MODIFIED => {
+                return match cached {
+                    Some(entry) => Ok(ApiResponse::Cached(entry.body)),
+                    None => Err(eyre!(
+                        "Received 304 Not Modified for {url:?} with no cached entry to revalidate against"
+                    )),
+                };
+            }
+            Ok(response) if response.status.is_success() => {
+                if let Some(cache) = cache {
+                    let etag = response
+                        .headers
+                        .get("etag")
+                        .and_then(|v| v.to_str().ok())
+                        .map(str::to_string);
+                    let last_modified = response
+                        .headers
+                        .get("last-modified")
+                        .and_then(|v| v.to_str().ok())
+                        .map(str::to_string);
+                    let (no_store, max_age) = response
+                        .headers
+                        .get("cache-control")
+                        .and_then(|v| v.to_str().ok())
+                        .map(parse_cache_control)
+                        .unwrap_or((false, None));
+
+                    let body = String::from_utf8_lossy(&response.body).into_owned();
+
+                    if !no_store {
+                        cache
+                            .put(
+                                &key,
+                                CacheEntry {
+                                    etag,
+                                    last_modified,
+                                    max_age,
+                                    fetched_at: Utc::now().timestamp(),
+                                    body: body.clone(),
+                                },
+                            )
+                            .await?;
+                    }
+
+                    return Ok(ApiResponse::Cached(body));
+                }
+
+                return Ok(ApiResponse::Fresh(response));
+            }
+            Ok(response) if response.status.is_server_error() && retries < MAX_RETRIES => {
+                let wait = backoff_with_jitter(retries);
+                retries += 1;
+
+                println!(
+                    "{} - Request to {} with args {:?} got {}, retrying in {:?}",
+                    Local::now().format("%Y-%m-%d %H:%M:%S.%f"),
+                    &url,
+                    args,
+                    response.status,
+                    wait
+                );
+
+                sleep(wait).await;
+            }
+            Ok(response) => return Err(classify_status(response.status).into()),
+            Err(TransportError::Timeout) if retries < MAX_RETRIES => {
+                let wait = backoff_with_jitter(retries);
+                retries += 1;
+
+                println!(
+                    "{} - Request to {} with args {:?} timed out, retrying in {:?}",
+                    Local::now().format("%Y-%m-%d %H:%M:%S.%f"),
+                    &url,
+                    args,
+                    wait
+                );
+
+                sleep(wait).await;
+            }
+            Err(TransportError::Connect(_)) if retries < MAX_RETRIES => {
+                let wait = backoff_with_jitter(retries);
+                retries += 1;
+
+                println!(
+                    "{} - Request to {} with args {:?} failed to connect, retrying in {:?}",
+                    Local::now().format("%Y-%m-%d %H:%M:%S.%f"),
+                    &url,
+                    args,
+                    wait
+                );
+
+                sleep(wait).await;
+            }
+            Err(e) => {
+                return Err(e).with_context(|| {
+                    format!("Failed to request data from the LoLEsports API:{url:?} with args -> {args:?}")
+                })
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderMap;
+
+    fn response(status: StatusCode, headers: HeaderMap) -> HttpResponse {
+        HttpResponse {
+            status,
+            headers,
+            body: bytes::Bytes::new(),
+        }
+    }
+
+    #[test]
+    fn bucket_key_is_derived_from_the_url_path_only() {
+        assert_eq!(
+            bucket_key("https://esports-api.lolesports.com/persisted/gw/getLeagues"),
+            "GET:/persisted/gw/getLeagues"
+        );
+    }
+
+    #[test]
+    fn cache_key_folds_the_query_string_in_so_distinct_args_dont_collide() {
+        assert_ne!(
+            cache_key("https://x/getTeams", Some("leagueId=1")),
+            cache_key("https://x/getTeams", Some("leagueId=2")),
+        );
+    }
+
+    #[test]
+    fn retry_after_reads_the_header_in_seconds() {
+        let mut headers = HeaderMap::new();
+        headers.insert("retry-after", "7".parse().unwrap());
+        assert_eq!(
+            retry_after(&response(StatusCode::TOO_MANY_REQUESTS, headers)),
+            Duration::from_secs(7)
+        );
+    }
+
+    #[test]
+    fn retry_after_defaults_to_one_second_when_the_header_is_missing() {
+        assert_eq!(
+            retry_after(&response(StatusCode::TOO_MANY_REQUESTS, HeaderMap::new())),
+            Duration::from_secs(1)
+        );
+    }
+
+    #[test]
+    fn method_rate_limit_parses_the_first_window_of_a_riot_style_header() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-method-rate-limit", "100:10,1000:600".parse().unwrap());
+        assert_eq!(
+            method_rate_limit(&response(StatusCode::OK, headers)),
+            Some((100, Duration::from_secs(10)))
+        );
+    }
+
+    #[test]
+    fn method_rate_limit_falls_back_to_the_plain_rate_limit_header() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-rate-limit", "20:1".parse().unwrap());
+        assert_eq!(
+            method_rate_limit(&response(StatusCode::OK, headers)),
+            Some((20, Duration::from_secs(1)))
+        );
+    }
+
+    #[test]
+    fn method_rate_limit_is_none_when_neither_header_is_present() {
+        assert_eq!(method_rate_limit(&response(StatusCode::OK, HeaderMap::new())), None);
+    }
+
+    #[test]
+    fn backoff_with_jitter_grows_exponentially_and_caps_at_max_backoff() {
+        assert!(backoff_with_jitter(0) >= BASE_BACKOFF);
+        assert!(backoff_with_jitter(0) < BASE_BACKOFF * 2);
+
+        // Large attempts must saturate instead of overflowing or panicking.
+        let capped = backoff_with_jitter(64);
+        assert!(capped >= MAX_BACKOFF);
+        assert!(capped <= MAX_BACKOFF + BASE_BACKOFF);
+    }
+
+    #[test]
+    fn retry_after_clamps_an_absurd_header_instead_of_overflowing_instant() {
+        let mut headers = HeaderMap::new();
+        headers.insert("retry-after", "18446744073709551615".parse().unwrap());
+        let wait = retry_after(&response(StatusCode::TOO_MANY_REQUESTS, headers));
+
+        assert_eq!(wait, MAX_RETRY_AFTER);
+        // This is the operation that used to panic on overflow.
+        assert!(std::time::Instant::now().checked_add(wait).is_some());
+    }
+
+    #[test]
+    fn method_rate_limit_clamps_an_absurd_window_instead_of_overflowing_instant() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-method-rate-limit", "5:18446744073709551615".parse().unwrap());
+        let (cap, window) = method_rate_limit(&response(StatusCode::OK, headers)).unwrap();
+
+        assert_eq!(cap, 5);
+        assert_eq!(window, MAX_RETRY_AFTER);
+        assert!(std::time::Instant::now().checked_add(window).is_some());
+    }
+}
@@ -0,0 +1,240 @@
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
+use color_eyre::Result;
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+use tokio::time::{interval, Duration};
+
+use crate::service::caller::make_get_request;
+use crate::service::DataPull;
+use crate::utils::constants::lolesports;
+
+const POLL_INTERVAL: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TeamFrameStats {
+    pub total_gold: u64,
+    pub dragons: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PlayerFrameStats {
+    pub summoner_name: String,
+    pub kills: u32,
+    pub deaths: u32,
+    pub assists: u32,
+    pub total_gold: u64,
+}
+
+/// A single delta between one polled frame and the last one we yielded:
+/// gold, kills, dragons, and per-player stats as of `timestamp`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct FrameDelta {
+    pub timestamp: DateTime<Utc>,
+    pub game_state: String,
+    pub blue_team: TeamFrameStats,
+    pub red_team: TeamFrameStats,
+    pub players: Vec<PlayerFrameStats>,
+}
+
+/// The `window` feed: team-level gold/dragons and overall game state, on a
+/// coarse cadence.
+#[derive(Debug, Deserialize)]
+struct WindowFrame {
+    #[serde(rename = "rfc460Timestamp")]
+    rfc460_timestamp: DateTime<Utc>,
+    #[serde(rename = "gameState")]
+    game_state: String,
+    #[serde(rename = "blueTeam")]
+    blue_team: TeamFrameStats,
+    #[serde(rename = "redTeam")]
+    red_team: TeamFrameStats,
+}
+
+#[derive(Debug, Deserialize)]
+struct Window {
+    frames: Vec<WindowFrame>,
+}
+
+/// The `details` feed: per-player kill/death/assist/gold stats, keyed by the
+/// same `rfc460Timestamp` as its matching `window` frame.
+#[derive(Debug, Deserialize)]
+struct DetailsFrame {
+    #[serde(rename = "rfc460Timestamp")]
+    rfc460_timestamp: DateTime<Utc>,
+    participants: Vec<PlayerFrameStats>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Details {
+    frames: Vec<DetailsFrame>,
+}
+
+#[derive(Debug, Serialize)]
+struct WindowQuery<'a> {
+    #[serde(rename = "startingTime")]
+    starting_time: &'a str,
+}
+
+/// Rounds `timestamp` down to the nearest 10-second boundary and formats it
+/// as the feed's expected `startingTime`, e.g. `2024-01-01T00:00:00Z`.
+fn round_down_to_10s(timestamp: DateTime<Utc>) -> String {
+    let seconds = timestamp.timestamp();
+    let rounded = seconds - seconds.rem_euclid(10);
+    DateTime::<Utc>::from_timestamp(rounded, 0)
+        .unwrap_or(timestamp)
+        .format("%Y-%m-%dT%H:%M:%SZ")
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rounds_a_timestamp_down_to_the_nearest_10s_boundary() {
+        let timestamp = DateTime::parse_from_rfc3339("2024-01-01T00:00:07Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        assert_eq!(round_down_to_10s(timestamp), "2024-01-01T00:00:00Z");
+    }
+
+    #[test]
+    fn leaves_a_timestamp_already_on_a_10s_boundary_unchanged() {
+        let timestamp = DateTime::parse_from_rfc3339("2024-01-01T00:00:10Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        assert_eq!(round_down_to_10s(timestamp), "2024-01-01T00:00:10Z");
+    }
+}
+
+impl DataPull {
+    /// Long-polls both the `window` and `details` feeds for `game_id` on a
+    /// 10s cadence and streams every frame newer than the last one seen,
+    /// advancing `startingTime` as frames arrive. Team gold/dragons/game
+    /// state come from `window`; per-player kill/death/assist/gold come from
+    /// `details`, matched to its `window` frame by `rfc460Timestamp`. The
+    /// stream ends once the game transitions to `finished`.
+    ///
+    /// The two feeds share one rate-limit bucket per endpoint (not per
+    /// `game_id`) so streaming several games at once still respects the
+    /// method's real cap.
+    pub fn stream_live_game(&self, game_id: String) -> mpsc::Receiver<Result<FrameDelta>> {
+        let (tx, rx) = mpsc::channel(32);
+        let rate_limiter = self.rate_limiter.clone();
+        let transport = self.transport.clone();
+        let window_url = format!("{}/{}/{}", lolesports::FEED_BASE_URL, lolesports::WINDOW, game_id);
+        let details_url = format!("{}/{}/{}", lolesports::FEED_BASE_URL, lolesports::DETAILS, game_id);
+        let window_bucket = format!("GET:/livestats/{}", lolesports::WINDOW);
+        let details_bucket = format!("GET:/livestats/{}", lolesports::DETAILS);
+
+        tokio::spawn(async move {
+            let mut ticker = interval(POLL_INTERVAL);
+            let mut last_seen: Option<DateTime<Utc>> = None;
+            let mut starting_time = round_down_to_10s(Utc::now() - ChronoDuration::seconds(10));
+
+            loop {
+                ticker.tick().await;
+
+                let args = WindowQuery {
+                    starting_time: &starting_time,
+                };
+
+                let window_response = match make_get_request(
+                    &window_url,
+                    Some(&args),
+                    &rate_limiter,
+                    None,
+                    transport.as_ref(),
+                    Some(&window_bucket),
+                )
+                .await
+                {
+                    Ok(response) => response,
+                    Err(e) => {
+                        if tx.send(Err(e)).await.is_err() {
+                            return;
+                        }
+                        continue;
+                    }
+                };
+
+                let window: Window = match window_response.json().await {
+                    Ok(window) => window,
+                    Err(e) => {
+                        if tx.send(Err(e)).await.is_err() {
+                            return;
+                        }
+                        continue;
+                    }
+                };
+
+                let details_response = match make_get_request(
+                    &details_url,
+                    Some(&args),
+                    &rate_limiter,
+                    None,
+                    transport.as_ref(),
+                    Some(&details_bucket),
+                )
+                .await
+                {
+                    Ok(response) => response,
+                    Err(e) => {
+                        if tx.send(Err(e)).await.is_err() {
+                            return;
+                        }
+                        continue;
+                    }
+                };
+
+                let details: Details = match details_response.json().await {
+                    Ok(details) => details,
+                    Err(e) => {
+                        if tx.send(Err(e)).await.is_err() {
+                            return;
+                        }
+                        continue;
+                    }
+                };
+
+                for frame in window.frames {
+                    if let Some(last) = last_seen {
+                        if frame.rfc460_timestamp <= last {
+                            continue;
+                        }
+                    }
+
+                    last_seen = Some(frame.rfc460_timestamp);
+                    starting_time = round_down_to_10s(frame.rfc460_timestamp);
+
+                    let finished = frame.game_state.eq_ignore_ascii_case("finished");
+
+                    let players = details
+                        .frames
+                        .iter()
+                        .find(|details_frame| details_frame.rfc460_timestamp == frame.rfc460_timestamp)
+                        .map(|details_frame| details_frame.participants.clone())
+                        .unwrap_or_default();
+
+                    let delta = FrameDelta {
+                        timestamp: frame.rfc460_timestamp,
+                        game_state: frame.game_state,
+                        blue_team: frame.blue_team,
+                        red_team: frame.red_team,
+                        players,
+                    };
+
+                    if tx.send(Ok(delta)).await.is_err() {
+                        return;
+                    }
+
+                    if finished {
+                        return;
+                    }
+                }
+            }
+        });
+
+        rx
+    }
+}
@@ -0,0 +1,142 @@
+pub mod cache;
+pub mod caller;
+pub mod live_stream;
+pub mod rate_limiter;
+pub mod transport;
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use color_eyre::Result;
+use serde::Serialize;
+use tokio::sync::Mutex;
+
+use crate::data_pull::serde_models::{
+    LeagueForTournaments, Leagues, Player, Team, TeamsPlayers, Tournament, Wrapper,
+};
+use crate::service::cache::HttpCache;
+use crate::service::caller::make_get_request;
+use crate::service::rate_limiter::RateLimiter;
+use crate::service::transport::{HttpTransport, ReqwestTransport};
+use crate::utils::constants::lolesports;
+
+/// Drives a full sync of the LoLEsports API into memory: leagues, their
+/// tournaments, and every team and player, ready to be handed to a
+/// `dao::DatabaseOps` implementation for persistence.
+pub struct DataPull {
+    pub base_url: String,
+    pub leagues: Leagues,
+    pub tournaments: Vec<Tournament>,
+    pub teams: Vec<Team>,
+    pub players: Vec<Player>,
+    /// When set, responses are cached on disk and revalidated with
+    /// `If-None-Match`/`If-Modified-Since` instead of re-downloaded in full.
+    /// Left `None` in tests so `httpmock` requests always hit the network.
+    pub cache_dir: Option<PathBuf>,
+    /// How requests are actually sent. Defaults to a `reqwest`-backed
+    /// transport; swap it for a fake one to serve fixtures with no socket,
+    /// or a pre-configured client (custom proxy, connection pool, alternate
+    /// key). `Arc` so `stream_live_game`'s background task can share it.
+    transport: Arc<dyn HttpTransport>,
+    rate_limiter: Arc<Mutex<RateLimiter>>,
+}
+
+impl Default for DataPull {
+    fn default() -> Self {
+        Self {
+            base_url: lolesports::BASE_URL.to_string(),
+            leagues: Leagues { leagues: Vec::new() },
+            tournaments: Vec::new(),
+            teams: Vec::new(),
+            players: Vec::new(),
+            cache_dir: None,
+            transport: Arc::new(ReqwestTransport::new()),
+            rate_limiter: Arc::new(Mutex::new(RateLimiter::new())),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct LeagueIdQuery {
+    #[serde(rename = "leagueId")]
+    league_id: String,
+}
+
+impl DataPull {
+    /// Swaps in a custom transport, e.g. a fake one serving fixtures, or a
+    /// `ReqwestTransport::with_client` wrapping a pre-configured client.
+    pub fn with_transport(mut self, transport: impl HttpTransport + 'static) -> Self {
+        self.transport = Arc::new(transport);
+        self
+    }
+
+    fn cache(&self) -> Option<HttpCache> {
+        self.cache_dir.clone().map(HttpCache::new)
+    }
+
+    pub async fn fetch_leagues(&mut self) -> Result<()> {
+        let url = format!("{}/{}", self.base_url, lolesports::GET_LEAGUES);
+        let response = make_get_request::<()>(
+            &url,
+            None,
+            &self.rate_limiter,
+            self.cache().as_ref(),
+            self.transport.as_ref(),
+            None,
+        )
+        .await?;
+        let wrapper: Wrapper<Leagues> = response.json().await?;
+        self.leagues = wrapper.data;
+        Ok(())
+    }
+
+    pub async fn fetch_tournaments(&mut self) -> Result<()> {
+        let url = format!("{}/{}", self.base_url, lolesports::GET_TOURNAMENTS_FOR_LEAGUE);
+
+        for league in &self.leagues.leagues {
+            let args = LeagueIdQuery {
+                league_id: league.id.0.to_string(),
+            };
+            let response = make_get_request(
+                &url,
+                Some(&args),
+                &self.rate_limiter,
+                self.cache().as_ref(),
+                self.transport.as_ref(),
+                None,
+            )
+            .await?;
+            let wrapper: Wrapper<Vec<LeagueForTournaments>> = response.json().await?;
+
+            for league_for_tournaments in wrapper.data {
+                self.tournaments.extend(league_for_tournaments.tournaments);
+            }
+        }
+
+        Ok(())
+    }
+
+    pub async fn fetch_teams_and_players(&mut self) -> Result<()> {
+        let url = format!("{}/{}", self.base_url, lolesports::GET_TEAMS);
+        let response = make_get_request::<()>(
+            &url,
+            None,
+            &self.rate_limiter,
+            self.cache().as_ref(),
+            self.transport.as_ref(),
+            None,
+        )
+        .await?;
+        let wrapper: Wrapper<TeamsPlayers> = response.json().await?;
+
+        self.players = wrapper
+            .data
+            .teams
+            .iter()
+            .flat_map(|team| team.players.clone())
+            .collect();
+        self.teams = wrapper.data.teams;
+
+        Ok(())
+    }
+}
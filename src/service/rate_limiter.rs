@@ -0,0 +1,155 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Conservative default until a bucket has learned the real limit from an
+/// `X-Rate-Limit`/`X-Method-Rate-Limit` response header.
+const DEFAULT_CAP: u32 = 20;
+const DEFAULT_WINDOW: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Clone)]
+struct Bucket {
+    cap: u32,
+    count: u32,
+    window: Duration,
+    window_start: Instant,
+    blocked_until: Option<Instant>,
+}
+
+impl Bucket {
+    fn new() -> Self {
+        Self {
+            cap: DEFAULT_CAP,
+            count: 0,
+            window: DEFAULT_WINDOW,
+            window_start: Instant::now(),
+            blocked_until: None,
+        }
+    }
+}
+
+/// Whether a bucket had room for one more request right now.
+pub enum Admission {
+    /// A slot was reserved; the caller may send its request.
+    Ready,
+    /// No room yet — sleep this long, then call `check` again.
+    Wait(Duration),
+}
+
+/// A token-bucket-per-endpoint rate limiter shared across every fetch issued
+/// by a `DataPull`, so concurrent requests back off together instead of each
+/// discovering the Riot key's rate limit independently.
+///
+/// `check` is synchronous and does no sleeping itself: it's meant to be
+/// called while holding the limiter's mutex just long enough to inspect or
+/// reserve a slot. Callers must drop the lock before sleeping on a
+/// `Wait(duration)` and call `check` again afterwards — otherwise one bucket
+/// waiting out its window would hold the lock and block every other bucket
+/// too.
+#[derive(Debug, Default)]
+pub struct RateLimiter {
+    buckets: HashMap<String, Bucket>,
+}
+
+impl RateLimiter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reserves a slot in `key`'s bucket if one is free right now, otherwise
+    /// reports how long the caller should wait before asking again.
+    pub fn check(&mut self, key: &str) -> Admission {
+        let now = Instant::now();
+        let bucket = self.buckets.entry(key.to_string()).or_insert_with(Bucket::new);
+
+        if let Some(blocked_until) = bucket.blocked_until {
+            if now < blocked_until {
+                return Admission::Wait(blocked_until - now);
+            }
+            bucket.blocked_until = None;
+            bucket.window_start = now;
+            bucket.count = 0;
+        } else if now.duration_since(bucket.window_start) >= bucket.window {
+            bucket.window_start = now;
+            bucket.count = 0;
+        } else if bucket.count >= bucket.cap {
+            return Admission::Wait(bucket.window - now.duration_since(bucket.window_start));
+        }
+
+        bucket.count += 1;
+        Admission::Ready
+    }
+
+    /// Called after a `429`. Blocks `key`'s bucket for `retry_after` and, if
+    /// the server told us its real cap/interval, remembers it for next time.
+    pub fn penalize(&mut self, key: &str, retry_after: Duration, limit: Option<(u32, Duration)>) {
+        let bucket = self.buckets.entry(key.to_string()).or_insert_with(Bucket::new);
+        bucket.blocked_until = Some(Instant::now() + retry_after);
+        if let Some((cap, window)) = limit {
+            bucket.cap = cap;
+            bucket.window = window;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_request_in_a_window_is_admitted_immediately() {
+        let mut limiter = RateLimiter::new();
+        assert!(matches!(limiter.check("k"), Admission::Ready));
+    }
+
+    #[test]
+    fn exceeding_the_cap_reports_a_wait_instead_of_panicking_or_blocking() {
+        let mut limiter = RateLimiter::new();
+        limiter.buckets.insert(
+            "k".to_string(),
+            Bucket {
+                cap: 1,
+                count: 0,
+                window: Duration::from_secs(10),
+                window_start: Instant::now(),
+                blocked_until: None,
+            },
+        );
+
+        assert!(matches!(limiter.check("k"), Admission::Ready));
+        assert!(matches!(limiter.check("k"), Admission::Wait(_)));
+    }
+
+    #[test]
+    fn a_busy_bucket_does_not_affect_an_unrelated_bucket() {
+        let mut limiter = RateLimiter::new();
+        limiter.buckets.insert(
+            "busy".to_string(),
+            Bucket {
+                cap: 1,
+                count: 1,
+                window: Duration::from_secs(10),
+                window_start: Instant::now(),
+                blocked_until: None,
+            },
+        );
+
+        assert!(matches!(limiter.check("busy"), Admission::Wait(_)));
+        assert!(matches!(limiter.check("other"), Admission::Ready));
+    }
+
+    #[test]
+    fn penalize_blocks_the_bucket_for_retry_after() {
+        let mut limiter = RateLimiter::new();
+        limiter.penalize("k", Duration::from_secs(5), None);
+        assert!(matches!(limiter.check("k"), Admission::Wait(_)));
+    }
+
+    #[test]
+    fn penalize_learns_the_servers_reported_cap_and_window() {
+        let mut limiter = RateLimiter::new();
+        limiter.penalize("k", Duration::ZERO, Some((5, Duration::from_secs(2))));
+        let bucket = limiter.buckets.get("k").unwrap();
+        assert_eq!(bucket.cap, 5);
+        assert_eq!(bucket.window, Duration::from_secs(2));
+    }
+}
@@ -0,0 +1,15 @@
+/// Constants for the public (unofficial) LoLEsports API.
+pub mod lolesports {
+    pub const BASE_URL: &str = "https://esports-api.lolesports.com/persisted/gw";
+    pub const FEED_BASE_URL: &str = "https://feed.lolesports.com/livestats/v1";
+    pub const API_KEY: &str = "0TvQnueqKa5mxJntVWt0w4LpLfEkrV1Ta8rQBb9Z";
+    pub const DEFAULT_LOCALE: &str = "en-US";
+
+    pub const GET_LEAGUES: &str = "getLeagues";
+    pub const GET_TOURNAMENTS_FOR_LEAGUE: &str = "getTournamentsForLeague";
+    pub const GET_TEAMS: &str = "getTeams";
+    pub const GET_SCHEDULE: &str = "getSchedule";
+    pub const GET_LIVE: &str = "getLive";
+    pub const WINDOW: &str = "window";
+    pub const DETAILS: &str = "details";
+}
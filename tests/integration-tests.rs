@@ -13,9 +13,11 @@ use tokio::fs;
 use triforce_data_pull::{
     data_pull::serde_models::{
         League, Event, EventDetails, EventOutter, LeagueForTournaments, Leagues, LiveScheduleOutter,
-        LolesportsId, Player, ScheduleOutter, Team, TeamsPlayers, Tournament, Wrapper,
+        LolesportsId, Player, Region, Role, ScheduleOutter, Team, TeamStatus, TeamsPlayers,
+        Tournament, Wrapper,
     },
     service::DataPull,
+    service::transport::{ConditionalHeaders, HttpResponse, HttpTransport, TransportError},
     dao::DatabaseOps,
     utils::constants::lolesports,
 };
@@ -32,6 +34,67 @@ fn setup() -> DataPull {
     DataPull::default()
 }
 
+/// A transport that serves a single canned body for every request, so tests
+/// exercising `HttpTransport` injection don't need a mock server or a socket.
+#[cfg(test)]
+struct FakeTransport {
+    body: String,
+}
+
+#[cfg(test)]
+#[async_trait::async_trait]
+impl HttpTransport for FakeTransport {
+    async fn get(
+        &self,
+        _url: &str,
+        _query: Option<&str>,
+        _conditional: Option<&ConditionalHeaders>,
+    ) -> Result<HttpResponse, TransportError> {
+        Ok(HttpResponse {
+            status: reqwest::StatusCode::OK,
+            headers: reqwest::header::HeaderMap::new(),
+            body: bytes::Bytes::from(self.body.clone()),
+        })
+    }
+}
+
+/// Serves canned `window`/`details` feed bodies in sequence, keyed by which
+/// feed the request's URL is for, so `stream_live_game` can be tested with no
+/// socket and no real wall-clock waiting.
+#[cfg(test)]
+struct ScriptedLiveTransport {
+    window_bodies: std::sync::Mutex<std::collections::VecDeque<String>>,
+    details_bodies: std::sync::Mutex<std::collections::VecDeque<String>>,
+}
+
+#[cfg(test)]
+#[async_trait::async_trait]
+impl HttpTransport for ScriptedLiveTransport {
+    async fn get(
+        &self,
+        url: &str,
+        _query: Option<&str>,
+        _conditional: Option<&ConditionalHeaders>,
+    ) -> Result<HttpResponse, TransportError> {
+        let bodies = if url.contains(&format!("/{}/", lolesports::WINDOW)) {
+            &self.window_bodies
+        } else {
+            &self.details_bodies
+        };
+        let body = bodies
+            .lock()
+            .unwrap()
+            .pop_front()
+            .unwrap_or_else(|| json!({ "frames": [] }).to_string());
+
+        Ok(HttpResponse {
+            status: reqwest::StatusCode::OK,
+            headers: reqwest::header::HeaderMap::new(),
+            body: bytes::Bytes::from(body),
+        })
+    }
+}
+
 /// This integration test validates the correct functionality of the `fetch_leagues` function.
 ///
 /// The test sets up a mock HTTP server to provide predefined responses. It then initiates a data fetch operation
@@ -68,7 +131,7 @@ async fn test_fetch_leagues() -> Result<()> {
     assert_eq!(msi.id.0, 98767991325878492);
     assert_eq!(msi.slug, "msi");
     assert_eq!(msi.name, "MSI");
-    assert_eq!(msi.region, "INTERNATIONAL");
+    assert_eq!(msi.region, Region::International);
     assert_eq!(
         msi.image,
         "http://static.lolesports.com/leagues/1592594634248_MSIDarkBG.png"
@@ -106,8 +169,8 @@ async fn test_fetch_tournaments() -> Result<()> {
         League { 
             id: LolesportsId(9876799130299601), 
             slug: "lec".to_string(),
-            name: "LEC".to_string(), 
-            region: "EMEA".to_string(), 
+            name: "LEC".to_string(),
+            region: Region::Emea,
             image: "http://static.lolesports.com/leagues/1592516184297_LEC-01-FullonDark.png".to_string()
          });
     data_pull.fetch_tournaments().await?;
@@ -181,13 +244,13 @@ async fn test_fetch_teams_and_players() -> Result<()> {
         fnatic.background_image,
         Some("http://static.lolesports.com/teams/1632941274242_FNC.png".to_string())
     );
-    assert_eq!(fnatic.status, "active");
+    assert_eq!(fnatic.status, TeamStatus::Active);
     assert!(fnatic.home_league.is_some());
     assert_eq!(fnatic.players.len(), 8);
 
     let home_league = fnatic.home_league.clone().unwrap();
     assert_eq!(home_league.name, "LEC");
-    assert_eq!(home_league.region, "EMEA");
+    assert_eq!(home_league.region, Region::Emea);
     let player = fnatic.players.iter().find(|p| p.id.0 == 100356590519370319);
     assert!(player.is_some());
     let humanoid = player.unwrap();
@@ -199,9 +262,104 @@ async fn test_fetch_teams_and_players() -> Result<()> {
         humanoid.image,
         Some("http://static.lolesports.com/players/1674150706185_humanoid.png".to_string())
     );
-    assert_eq!(humanoid.role, "mid");
+    assert_eq!(humanoid.role, Role::Mid);
 
     mock.assert();
 
     Ok(())
 }
+
+/// Exercises `HttpTransport` injection directly — no mock server, no socket —
+/// the path `ReqwestTransport`'s doc comment advertises as the point of the
+/// trait.
+#[tokio::test]
+async fn test_fetch_leagues_via_a_fake_transport() -> Result<()> {
+    let mock_data = read_json_file("tests/test_data/get_leagues.json").await?;
+    let transport = FakeTransport {
+        body: mock_data.to_string(),
+    };
+
+    let mut data_pull = setup().with_transport(transport);
+    data_pull.base_url = "https://example.invalid".to_string();
+
+    data_pull.fetch_leagues().await?;
+
+    assert_eq!(data_pull.leagues.leagues.len(), 45);
+
+    Ok(())
+}
+
+/// Feeds `stream_live_game` scripted `window`/`details` responses and checks
+/// that frames are merged by timestamp, a duplicate timestamp within the same
+/// `window` response is skipped, and the stream ends on `"finished"`.
+#[tokio::test(start_paused = true)]
+async fn test_stream_live_game_merges_feeds_dedupes_and_stops_on_finished() -> Result<()> {
+    let t1 = "2024-01-01T00:00:00Z";
+    let t2 = "2024-01-01T00:00:10Z";
+
+    let window_body = json!({
+        "frames": [
+            {
+                "rfc460Timestamp": t1,
+                "gameState": "in_game",
+                "blueTeam": { "total_gold": 1000, "dragons": [] },
+                "redTeam": { "total_gold": 900, "dragons": [] }
+            },
+            {
+                "rfc460Timestamp": t1,
+                "gameState": "in_game",
+                "blueTeam": { "total_gold": 1000, "dragons": [] },
+                "redTeam": { "total_gold": 900, "dragons": [] }
+            },
+            {
+                "rfc460Timestamp": t2,
+                "gameState": "finished",
+                "blueTeam": { "total_gold": 2000, "dragons": ["infernal"] },
+                "redTeam": { "total_gold": 1800, "dragons": [] }
+            }
+        ]
+    })
+    .to_string();
+
+    let details_body = json!({
+        "frames": [
+            {
+                "rfc460Timestamp": t1,
+                "participants": [
+                    { "summoner_name": "Faker", "kills": 1, "deaths": 0, "assists": 2, "total_gold": 3000 }
+                ]
+            },
+            {
+                "rfc460Timestamp": t2,
+                "participants": [
+                    { "summoner_name": "Faker", "kills": 3, "deaths": 0, "assists": 4, "total_gold": 6000 }
+                ]
+            }
+        ]
+    })
+    .to_string();
+
+    let transport = ScriptedLiveTransport {
+        window_bodies: std::sync::Mutex::new(std::collections::VecDeque::from([window_body])),
+        details_bodies: std::sync::Mutex::new(std::collections::VecDeque::from([details_body])),
+    };
+
+    let data_pull = setup().with_transport(transport);
+    let mut rx = data_pull.stream_live_game("100500".to_string());
+
+    let first = rx.recv().await.unwrap()?;
+    assert_eq!(first.game_state, "in_game");
+    assert_eq!(first.players.len(), 1);
+    assert_eq!(first.players[0].kills, 1);
+
+    let second = rx.recv().await.unwrap()?;
+    assert_eq!(second.game_state, "finished");
+    assert_eq!(second.players[0].kills, 3);
+    assert_eq!(second.blue_team.dragons, vec!["infernal".to_string()]);
+
+    // The duplicate `t1` frame and anything past `finished` must not surface
+    // as additional messages.
+    assert!(rx.recv().await.is_none());
+
+    Ok(())
+}